@@ -0,0 +1,136 @@
+use anyhow::{bail, Context, Result};
+use lofty::{Accessor, ItemKey, Probe, Tag, TagExt, TaggedFileExt};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+const AUDIO_EXTENSIONS: &[&str] = &["mp3", "m4a", "flac"];
+
+// Strips "01 - ", "1.", "CD1-03 " style disc/track-number prefixes so a file name compares
+// cleanly against Apple's track name.
+static TRACK_PREFIX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^(?:cd\s*\d+[\s\-.]*)?\d{1,3}[\s\-.]+").unwrap());
+
+fn normalized_stem(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    Some(TRACK_PREFIX.replace(stem, "").trim().to_lowercase())
+}
+
+// Real rips are almost always laid out as `Artist/Album/01 - Track Title.ext`, so the file name
+// itself rarely repeats the artist. Match on the track name alone, and only use the artist name
+// as a tiebreaker if that leaves more than one candidate in the directory. If the tiebreak still
+// doesn't narrow it to exactly one file, bail instead of guessing — embedding into the wrong file
+// permanently clobbers that file's existing tag.
+fn find_match(dir: &Path, track_name: &str, artist_name: &str) -> Result<PathBuf> {
+    let track_name = track_name.to_lowercase();
+    let artist_name = artist_name.to_lowercase();
+
+    let mut candidates: Vec<PathBuf> = std::fs::read_dir(dir)
+        .context("Failed to read embed directory")?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| AUDIO_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+                .unwrap_or(false)
+        })
+        .filter(|path| {
+            normalized_stem(path)
+                .map(|stem| stem.contains(&track_name))
+                .unwrap_or(false)
+        })
+        .collect();
+
+    if candidates.len() > 1 {
+        let narrowed: Vec<PathBuf> = candidates
+            .iter()
+            .filter(|path| {
+                normalized_stem(path)
+                    .map(|stem| stem.contains(&artist_name))
+                    .unwrap_or(false)
+            })
+            .cloned()
+            .collect();
+
+        if narrowed.len() == 1 {
+            return Ok(narrowed.into_iter().next().unwrap());
+        }
+
+        if narrowed.len() > 1 {
+            candidates = narrowed;
+        }
+    }
+
+    if candidates.len() > 1 {
+        bail!(
+            "Ambiguous match for {} - {}: {} candidate files in {}",
+            track_name,
+            artist_name,
+            candidates.len(),
+            dir.display()
+        );
+    }
+
+    candidates
+        .into_iter()
+        .next()
+        .with_context(|| format!("No local file matched {} - {}", track_name, artist_name))
+}
+
+/// Finds the local audio file in `dir` matching `track_name`/`artist_name` and writes `lrc_text`
+/// as the lyrics item of its primary tag via `lofty` — a `USLT` frame for MP3, `©lyr` for
+/// M4A/ALAC. No `SYLT` frame or `----:com.apple.iTunes:LYRICS` atom is written.
+pub fn embed_lyrics(dir: &Path, track_name: &str, artist_name: &str, lrc_text: &str) -> Result<()> {
+    let path = find_match(dir, track_name, artist_name)?;
+
+    let mut tagged_file = Probe::open(&path)
+        .context("Failed to probe audio file")?
+        .read()
+        .context("Failed to read audio file")?;
+
+    let tag_type = tagged_file.primary_tag_type();
+    if tagged_file.primary_tag().is_none() {
+        tagged_file.insert_tag(Tag::new(tag_type));
+    }
+    let tag = tagged_file.primary_tag_mut().unwrap();
+
+    tag.insert_text(ItemKey::Lyrics, lrc_text.to_string());
+    tag.save_to_path(&path).context("Failed to save embedded lyrics")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn matches_track_number_prefixed_file_with_no_artist_in_the_name() {
+        let dir = std::env::temp_dir().join(format!("uta-embed-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let file_path = dir.join("01 - Take On Me.m4a");
+        fs::write(&file_path, b"").unwrap();
+
+        let found = find_match(&dir, "Take On Me", "a-ha").unwrap();
+        assert_eq!(found, file_path);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn bails_on_ambiguous_match_instead_of_picking_arbitrarily() {
+        let dir = std::env::temp_dir().join(format!("uta-embed-test-ambiguous-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        fs::write(dir.join("01 - Take On Me.m4a"), b"").unwrap();
+        fs::write(dir.join("02 - Take On Me (Live).m4a"), b"").unwrap();
+
+        let err = find_match(&dir, "Take On Me", "a-ha").unwrap_err();
+        assert!(err.to_string().contains("Ambiguous match"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
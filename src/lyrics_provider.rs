@@ -0,0 +1,36 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use lrc::Lyrics;
+use std::fmt;
+
+/// A provider declining to show lyrics it otherwise found, due to auth/licensing restrictions,
+/// as opposed to genuinely having nothing (`Ok(None)`) or failing outright (any other `Err`).
+/// Callers can `downcast_ref` an `anyhow::Error` to this without caring which provider raised it.
+#[derive(Debug)]
+pub struct AccessDenied;
+
+impl fmt::Display for AccessDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not authorized to show these lyrics")
+    }
+}
+
+impl std::error::Error for AccessDenied {}
+
+/// A secondary source of lyrics, consulted when Apple Music doesn't have any for a track.
+#[async_trait]
+pub trait LyricsProvider {
+    /// Human-readable name for diagnostics, e.g. "Musixmatch".
+    fn name(&self) -> &str;
+
+    /// Looks up lyrics for a track. `Ok(None)` means the provider genuinely has nothing for
+    /// this track; use `Err` for anything else (network failure, access denial via
+    /// [`AccessDenied`], ...).
+    async fn fetch(
+        &self,
+        artist: &str,
+        title: &str,
+        album: &str,
+        duration_seconds: f64,
+    ) -> Result<Option<Lyrics>>;
+}
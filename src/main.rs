@@ -1,18 +1,48 @@
+mod embed;
+mod lyrics_provider;
+mod musixmatch;
+mod session_cache;
 mod types;
 
-use crate::types::{AlbumCatlogs, SongCatlogs, Storefronts};
+use crate::lyrics_provider::{AccessDenied, LyricsProvider};
+use crate::musixmatch::MusixmatchProvider;
+use crate::types::{
+    AlbumCatlogs, PlaylistCatlogs, SimpleAlbumTrack, SimpleAlbumTracks, SimpleLyrics, SongCatlogs,
+    Storefronts,
+};
 use anyhow::{anyhow, bail, Context, Result};
+use futures::stream::{self, StreamExt};
 use reqwest::{header, Client, Error, Response, Url};
 use std::io::Write;
+use std::path::PathBuf;
 use std::str::FromStr;
 use xmlem::display::Config;
 use xmlem::{Document, Selector};
 
-use clap::{ArgGroup, Parser};
+use clap::{ArgGroup, Parser, ValueEnum};
 use lrc::{IDTag, Lyrics, TimeTag};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+#[derive(Clone, Copy, ValueEnum)]
+enum FallbackProvider {
+    Musixmatch,
+}
+
+// The only place that needs to know about concrete provider types — everything downstream
+// (`Uta::fallback`, `fetch_fallback`) only ever sees a `dyn LyricsProvider`.
+fn build_fallback(
+    choice: Option<FallbackProvider>,
+    client: &Client,
+) -> Option<Box<dyn LyricsProvider + Send + Sync>> {
+    match choice {
+        Some(FallbackProvider::Musixmatch) => {
+            Some(Box::new(MusixmatchProvider::new(client.clone())))
+        }
+        None => None,
+    }
+}
+
 pub fn nice_xml(xml: String) -> String {
     Document::from_str(&xml)
         .expect("Failed to parse xml")
@@ -35,18 +65,20 @@ static TTML_TIMETAG_MS: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"^((?P<minute>\d{1,2}):)?(?P<second>\d{1,2})\.(?P<frames>\d{3})$").unwrap()
 });
 
-pub fn ttml_timetag_to_lrc_timetag(ttml: &str) -> Result<TimeTag> {
+// Formats a TTML timetag as a bare lrc `mm:ss.xx` string — no surrounding `[` `]` or `<` `>`,
+// those are added by callers. Used directly (rather than via `TimeTag`'s `Display`) by the
+// inline word tags in `render_syllable_line`, since nothing guarantees `TimeTag::fmt` emits a
+// bare timestamp instead of a full `[mm:ss.xx]` line tag.
+fn ttml_timetag_to_lrc_string(ttml: &str) -> Result<String> {
     if let Some(ms) = TTML_TIMETAG_MS.captures(ttml) {
         let min: u32 = ms
             .name("minute")
             .map(|x| x.as_str().parse().unwrap())
             .unwrap_or(0);
         let sec: u32 = ms.name("second").unwrap().as_str().parse().unwrap();
-        let ms: u32 = ms.name("frames").unwrap().as_str().parse().unwrap();
-
-        let ts = format!("{:02}:{:02}.{}", min, sec, ms / 10);
+        let frames: u32 = ms.name("frames").unwrap().as_str().parse().unwrap();
 
-        return TimeTag::from_str(ts).context("Failed to parse lrc timetag");
+        return Ok(format!("{:02}:{:02}.{:02}", min, sec, frames / 10));
     }
 
     if let Some(hms) = TTML_TIMETAG_HMS.captures(ttml) {
@@ -59,14 +91,99 @@ pub fn ttml_timetag_to_lrc_timetag(ttml: &str) -> Result<TimeTag> {
             .map(|x| x.as_str().parse().unwrap())
             .unwrap_or(0);
         let sec: u32 = hms.name("second").unwrap().as_str().parse().unwrap();
-        let ms: u32 = hms.name("frames").unwrap().as_str().parse().unwrap();
+        let frames: u32 = hms.name("frames").unwrap().as_str().parse().unwrap();
+
+        return Ok(format!(
+            "{:02}:{:02}.{:02}",
+            hour * 60 + min,
+            sec,
+            frames / 10
+        ));
+    }
+
+    Err(anyhow!("Invalid pattern"))
+}
+
+pub fn ttml_timetag_to_lrc_timetag(ttml: &str) -> Result<TimeTag> {
+    let ts = ttml_timetag_to_lrc_string(ttml)?;
+    TimeTag::from_str(ts).context("Failed to parse lrc timetag")
+}
+
+fn is_blank_text(xml: &Document, node: &xmlem::Node) -> bool {
+    node.as_text()
+        .map(|text| text.as_str(xml).trim().is_empty())
+        .unwrap_or(false)
+}
 
-        let ts = format!("{:02}:{:02}.{}", hour * 60 + min, sec, ms / 10);
+// Renders a `<p>` that carries per-word `<span begin="..." end="...">` children into the text of
+// a single Enhanced LRC (A2) line: `<mm:ss.xx>word` runs only, since the line's own `[mm:ss.xx]`
+// tag is added by the caller through `add_timed_line`. Whatever whitespace TTML put *between* the spans preserved so words don't glue together.
+// Whitespace-only text nodes before the first span or after the last (pretty-printed XML
+// indentation, mostly) are trimmed instead of being baked into the line.
+fn render_syllable_line(xml: &Document, p_element: xmlem::Element) -> Result<String> {
+    let mut nodes = p_element.child_nodes(xml);
 
-        return TimeTag::from_str(ts).context("Failed to parse lrc timetag");
+    while nodes.first().map(|node| is_blank_text(xml, node)).unwrap_or(false) {
+        nodes.remove(0);
+    }
+    while nodes.last().map(|node| is_blank_text(xml, node)).unwrap_or(false) {
+        nodes.pop();
     }
 
-    Err(anyhow!("Invalid pattern"))
+    let mut line = String::new();
+
+    for node in nodes {
+        if let Some(span) = node.as_element() {
+            let Some(begin) = span.attribute(xml, "begin") else {
+                bail!("Span has no begin attribute")
+            };
+            let word_tag = ttml_timetag_to_lrc_string(begin)?;
+
+            let word = span
+                .child_nodes(xml)
+                .first()
+                .context("Span has no text")?
+                .as_text()
+                .context("Span child is not text")?
+                .as_str(xml);
+
+            line.push_str(&format!("<{}>", word_tag));
+            line.push_str(word);
+        } else if let Some(text) = node.as_text() {
+            line.push_str(text.as_str(xml));
+        }
+    }
+
+    Ok(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn syllable_line_renders_a2_word_tags_with_preserved_whitespace() {
+        let xml = r#"<tt xmlns="http://www.w3.org/ns/ttml"><body><div>
+            <p begin="12.300" end="13.000">
+                <span begin="12.300" end="12.800">Hello</span> <span begin="12.800" end="13.000">world</span>
+            </p>
+        </div></body></tt>"#;
+
+        let doc = Document::from_str(xml).expect("test fixture should parse");
+        let p = doc
+            .root()
+            .query_selector(&doc, &Selector::new("p").unwrap())
+            .expect("test fixture has a <p>");
+
+        let line = render_syllable_line(&doc, p).expect("rendering should succeed");
+
+        assert_eq!(line, "<00:12.30>Hello <00:12.80>world");
+    }
+
+    #[test]
+    fn ttml_timetag_to_lrc_string_pads_sub_100ms_frames() {
+        assert_eq!(ttml_timetag_to_lrc_string("01:02.050").unwrap(), "01:02.05");
+    }
 }
 
 pub fn ttml_to_lrc(xml: Document, author: &str, name: &str) -> Result<Lyrics> {
@@ -83,27 +200,29 @@ pub fn ttml_to_lrc(xml: Document, author: &str, name: &str) -> Result<Lyrics> {
 
     for div_element in body.query_selector_all(&xml, &Selector::new("div").unwrap()) {
         for p_element in div_element.query_selector_all(&xml, &Selector::new("p").unwrap()) {
-            if p_element
-                .query_selector(&xml, &Selector::new("span").unwrap())
-                .is_some()
-            {
-                bail!("Syllable lyrics is not supported");
-            }
-
             let Some(begin) = p_element.attribute(&xml, "begin") else {
                 bail!("No begin attribute")
             };
-
-            let text = p_element
-                .child_nodes(&xml)
-                .first()
-                .unwrap()
-                .as_text()
-                .unwrap()
-                .as_str(&xml);
             let timetag = ttml_timetag_to_lrc_timetag(begin)?;
 
-            lyrics.add_timed_line(timetag, text.to_string()).unwrap();
+            let has_spans = p_element
+                .query_selector(&xml, &Selector::new("span").unwrap())
+                .is_some();
+
+            let text = if has_spans {
+                render_syllable_line(&xml, p_element)?
+            } else {
+                p_element
+                    .child_nodes(&xml)
+                    .first()
+                    .unwrap()
+                    .as_text()
+                    .unwrap()
+                    .as_str(&xml)
+                    .to_string()
+            };
+
+            lyrics.add_timed_line(timetag, text).unwrap();
         }
     }
 
@@ -116,6 +235,10 @@ struct Uta {
     access_token: String,
     store_front: String,
     language: String,
+    fallback: Option<Box<dyn LyricsProvider + Send + Sync>>,
+    embed_dir: Option<PathBuf>,
+    translation: bool,
+    romaji: bool,
 }
 
 #[derive(Parser)]
@@ -137,12 +260,56 @@ struct Options {
     /// Apple media token
     #[arg(short = 't', long = "token", env = "APPLE_MEDIA_TOKEN")]
     token: String,
+    /// Secondary lyrics provider to try when Apple Music has none
+    #[arg(long = "fallback", value_enum)]
+    fallback: Option<FallbackProvider>,
+    /// Ignore the cached session and re-scrape the access token and storefront
+    #[arg(long = "refresh-token", default_value_t = false)]
+    refresh_token: bool,
+    /// Embed lyrics directly into matching local audio files in this directory
+    #[arg(long = "embed")]
+    embed: Option<PathBuf>,
+    /// How many tracks to fetch/write lyrics for at once
+    #[arg(
+        long = "concurrency",
+        default_value_t = 4,
+        value_parser = clap::value_parser!(usize).range(1..)
+    )]
+    concurrency: usize,
+    /// Also save the translated lyrics, in the storefront's language, as a separate file
+    #[arg(long = "translation", default_value_t = false)]
+    translation: bool,
+    /// Also save the romanized/pronunciation lyrics as a separate file
+    #[arg(long = "romaji", default_value_t = false)]
+    romaji: bool,
 }
 
 impl Uta {
-    async fn handle_raw_url(&self, url: String, syllable: bool, lrc: bool) -> Result<()> {
+    async fn handle_raw_url(
+        &self,
+        url: String,
+        syllable: bool,
+        lrc: bool,
+        concurrency: usize,
+    ) -> Result<()> {
         let parsed = Url::parse(&url).context("Failed to parse url")?;
 
+        let is_playlist = parsed
+            .path_segments()
+            .context("Failed to get path segments")?
+            .any(|segment| segment == "playlist");
+
+        if is_playlist {
+            let id = parsed
+                .path_segments()
+                .context("Failed to get path segments")?
+                .last()
+                .context("No path segments")?;
+            self.save_playlist_lyrics(id.to_string(), syllable, lrc, concurrency)
+                .await?;
+            return Ok(());
+        }
+
         let pairs = parsed.query_pairs();
 
         if pairs.count() == 0 {
@@ -151,7 +318,7 @@ impl Uta {
                 .context("Failed to get path segments")?
                 .last()
                 .context("No path segments")?;
-            self.save_album_lyrics(id.to_string(), syllable, lrc)
+            self.save_album_lyrics(id.to_string(), syllable, lrc, concurrency)
                 .await?;
             return Ok(());
         }
@@ -167,7 +334,7 @@ impl Uta {
                 .context("Failed to get path segments")?
                 .last()
                 .context("No path segments")?;
-            self.save_album_lyrics(id.to_string(), syllable, lrc)
+            self.save_album_lyrics(id.to_string(), syllable, lrc, concurrency)
                 .await?;
         }
 
@@ -188,13 +355,226 @@ impl Uta {
             )
             .query(&[
                 ("l", self.language.clone()),
-                ("include[songs]", "album,lyrics,syllable-lyrics".to_string()),
+                (
+                    "include[songs]",
+                    "album,lyrics,syllable-lyrics,translations,pronunciations".to_string(),
+                ),
             ])
             .send()
             .await
     }
 
-    async fn save_album_lyrics(&self, album_id: String, syllable: bool, lrc: bool) -> Result<()> {
+    /// Tries the configured fallback provider, printing a diagnostic and returning `None`
+    /// instead of failing the whole run on denial or network errors.
+    async fn fetch_fallback(
+        &self,
+        artist: &str,
+        title: &str,
+        album: &str,
+        duration_seconds: f64,
+    ) -> Option<Lyrics> {
+        let provider = self.fallback.as_ref()?;
+
+        match provider.fetch(artist, title, album, duration_seconds).await {
+            Ok(lyrics) => lyrics,
+            Err(err) => {
+                if err.downcast_ref::<AccessDenied>().is_some() {
+                    println!(
+                        "{} is not authorized to show lyrics for {} - {}",
+                        provider.name(),
+                        title,
+                        artist
+                    );
+                } else {
+                    println!("{} fallback failed: {}", provider.name(), err);
+                }
+                None
+            }
+        }
+    }
+
+    /// Embeds `lrc_text` into the local audio file matching `track_name`/`artist_name`, if
+    /// `--embed` was given. Failures are reported but don't abort the run, matching how a
+    /// missing lyric for one track doesn't stop the rest.
+    fn embed_track_lyrics(&self, track_name: &str, artist_name: &str, lrc_text: &str) {
+        let Some(embed_dir) = &self.embed_dir else {
+            return;
+        };
+
+        if let Err(err) = embed::embed_lyrics(embed_dir, track_name, artist_name, lrc_text) {
+            println!(
+                "Failed to embed lyrics for {} - {}: {}",
+                track_name, artist_name, err
+            );
+        }
+    }
+
+    /// Writes `variant` (translation or pronunciation lyrics) as `{track} - {artist}.{suffix}.lrc`,
+    /// doing nothing if Apple didn't return one for this track.
+    fn write_variant_lyrics(
+        &self,
+        variant: &SimpleLyrics,
+        folder_name: Option<&str>,
+        track_name: &str,
+        artist_name: &str,
+        suffix: &str,
+    ) -> Result<()> {
+        let Some(lyric) = variant.data.get(0) else {
+            return Ok(());
+        };
+
+        let xml =
+            Document::from_str(&lyric.attributes.ttml.clone()).expect("Failed to parse xml");
+        let text = ttml_to_lrc(xml, artist_name, track_name)
+            .context("Failed to convert")?
+            .to_string();
+
+        let file_name = match folder_name {
+            Some(folder) => format!("{}/{} - {}.{}.lrc", folder, track_name, artist_name, suffix),
+            None => format!("{} - {}.{}.lrc", track_name, artist_name, suffix),
+        };
+
+        let mut file = std::fs::File::create(file_name).context("Failed to create file")?;
+        file.write_all(text.as_bytes())
+            .context("Failed to write file")?;
+
+        Ok(())
+    }
+
+    /// Writes (and optionally embeds) the lyrics for a single album/playlist track. Shared by
+    /// `save_album_lyrics` and `save_playlist_lyrics`, which both just differ in how they
+    /// gather the track list.
+    async fn save_track_lyrics(
+        &self,
+        track: &SimpleAlbumTrack,
+        folder_name: &str,
+        album_name: &str,
+        syllable: bool,
+        lrc: bool,
+    ) -> Result<()> {
+        let lyrics = track.relationships.get_lyrics(syllable);
+        let file_name = format!(
+            "{}/{} - {}.{}",
+            folder_name,
+            track.attributes.name,
+            track.attributes.artist_name,
+            if lrc { "lrc" } else { "ttml" }
+        );
+        let track_lyric = lyrics.data.get(0);
+        if let Some(lyric) = track_lyric {
+            let buf = if lrc {
+                let xml = Document::from_str(&lyric.attributes.ttml.clone())
+                    .expect("Failed to parse xml");
+                ttml_to_lrc(xml, &track.attributes.artist_name, &track.attributes.name)
+                    .context("Failed to convert")?
+                    .to_string()
+            } else {
+                nice_xml(lyric.attributes.ttml.clone())
+            };
+            let mut file = std::fs::File::create(file_name).context("Failed to create file")?;
+            file.write_all(buf.as_bytes())
+                .context("Failed to write file")?;
+
+            if self.embed_dir.is_some() {
+                let lrc_text = if lrc {
+                    buf.clone()
+                } else {
+                    let xml = Document::from_str(&lyric.attributes.ttml.clone())
+                        .expect("Failed to parse xml");
+                    ttml_to_lrc(xml, &track.attributes.artist_name, &track.attributes.name)
+                        .context("Failed to convert")?
+                        .to_string()
+                };
+                self.embed_track_lyrics(
+                    &track.attributes.name,
+                    &track.attributes.artist_name,
+                    &lrc_text,
+                );
+            }
+
+            if self.translation {
+                self.write_variant_lyrics(
+                    &track.relationships.translations,
+                    Some(folder_name),
+                    &track.attributes.name,
+                    &track.attributes.artist_name,
+                    "translation",
+                )?;
+            }
+            if self.romaji {
+                self.write_variant_lyrics(
+                    &track.relationships.pronunciations,
+                    Some(folder_name),
+                    &track.attributes.name,
+                    &track.attributes.artist_name,
+                    "romaji",
+                )?;
+            }
+        } else if let Some(fallback_lyrics) = self
+            .fetch_fallback(
+                &track.attributes.artist_name,
+                &track.attributes.name,
+                album_name,
+                track
+                    .attributes
+                    .duration_in_millis
+                    .map(|ms| ms as f64 / 1000.0)
+                    .unwrap_or(0.0),
+            )
+            .await
+        {
+            let file_name = format!(
+                "{}/{} - {}.lrc",
+                folder_name, track.attributes.name, track.attributes.artist_name
+            );
+            let lrc_text = fallback_lyrics.to_string();
+            let mut file = std::fs::File::create(file_name).context("Failed to create file")?;
+            file.write_all(lrc_text.as_bytes())
+                .context("Failed to write file")?;
+
+            self.embed_track_lyrics(
+                &track.attributes.name,
+                &track.attributes.artist_name,
+                &lrc_text,
+            );
+        } else {
+            println!(
+                "{} - {} has no lyrics",
+                track.attributes.name, track.attributes.artist_name
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Runs `save_track_lyrics` for every track, `concurrency` at a time, so the per-track work
+    /// (file writes, embedding and any fallback provider request) overlaps instead of running
+    /// in sequence.
+    async fn save_tracks_lyrics(
+        &self,
+        tracks: &[SimpleAlbumTrack],
+        folder_name: &str,
+        album_name: &str,
+        syllable: bool,
+        lrc: bool,
+        concurrency: usize,
+    ) -> Result<()> {
+        stream::iter(tracks)
+            .map(|track| self.save_track_lyrics(track, folder_name, album_name, syllable, lrc))
+            .buffer_unordered(concurrency)
+            .collect::<Vec<Result<()>>>()
+            .await
+            .into_iter()
+            .collect::<Result<()>>()
+    }
+
+    async fn save_album_lyrics(
+        &self,
+        album_id: String,
+        syllable: bool,
+        lrc: bool,
+        concurrency: usize,
+    ) -> Result<()> {
         println!("Getting album info...");
 
         let url = format!(
@@ -220,38 +600,76 @@ impl Uta {
         let folder_name = format!("{} - {}", attributes.name, attributes.artist_name);
         std::fs::create_dir(&folder_name).context("Failed to create folder")?;
 
-        for track in tracks {
-            let lyrics = track.relationships.get_lyrics(syllable);
-            let file_name = format!(
-                "{}/{} - {}.{}",
-                folder_name,
-                track.attributes.name,
-                track.attributes.artist_name,
-                if lrc { "lrc" } else { "ttml" }
-            );
-            let track_lyric = lyrics.data.get(0);
-            if let Some(lyric) = track_lyric {
-                let buf = if lrc {
-                    let xml = Document::from_str(&lyric.attributes.ttml.clone())
-                        .expect("Failed to parse xml");
-                    ttml_to_lrc(xml, &track.attributes.artist_name, &track.attributes.name)
-                        .context("Failed to convert")?
-                        .to_string()
-                } else {
-                    nice_xml(lyric.attributes.ttml.clone())
-                };
-                let mut file = std::fs::File::create(file_name).context("Failed to create file")?;
-                file.write_all(buf.as_bytes())
-                    .context("Failed to write file")?;
-            } else {
-                println!(
-                    "{} - {} has no lyrics",
-                    track.attributes.name, track.attributes.artist_name
-                );
-            }
+        self.save_tracks_lyrics(
+            tracks,
+            &folder_name,
+            &attributes.name,
+            syllable,
+            lrc,
+            concurrency,
+        )
+        .await
+    }
+
+    async fn save_playlist_lyrics(
+        &self,
+        playlist_id: String,
+        syllable: bool,
+        lrc: bool,
+        concurrency: usize,
+    ) -> Result<()> {
+        println!("Getting playlist info...");
+
+        let url = format!(
+            "https://amp-api.music.apple.com/v1/catalog/{}/playlists/{}",
+            self.store_front, playlist_id
+        );
+
+        let result = self
+            .get_response(url)
+            .await
+            .context("Failed to send request to Apple Music")?;
+
+        let playlist_catlogs: PlaylistCatlogs =
+            result.json().await.context("Failed to parse json")?;
+
+        let catlog_data = playlist_catlogs
+            .data
+            .into_iter()
+            .next()
+            .context("No playlist found")?;
+
+        let attributes = catlog_data.attributes;
+        let mut tracks = catlog_data.relationships.tracks.data;
+        let mut next = catlog_data.relationships.tracks.next;
+
+        while let Some(next_path) = next {
+            let next_url = format!("https://amp-api.music.apple.com{}", next_path);
+            let page_result = self
+                .get_response(next_url)
+                .await
+                .context("Failed to fetch next playlist page")?;
+            let page: SimpleAlbumTracks =
+                page_result.json().await.context("Failed to parse json")?;
+
+            tracks.extend(page.data);
+            next = page.next;
         }
 
-        Ok(())
+        println!("Saving lyrics...");
+
+        let folder_name = attributes.name.clone();
+        std::fs::create_dir(&folder_name).context("Failed to create folder")?;
+
+        self.save_tracks_lyrics(
+            &tracks,
+            &folder_name,
+            &attributes.name,
+            syllable,
+            lrc,
+            concurrency,
+        )
+        .await
     }
 
     async fn save_song_lyrics(&self, song_id: String, syllable: bool, lrc: bool) -> Result<()> {
@@ -300,6 +718,57 @@ impl Uta {
             let mut file = std::fs::File::create(file_name).context("Failed to create file")?;
             file.write_all(buf.as_bytes())
                 .context("Failed to write file")?;
+
+            if self.embed_dir.is_some() {
+                let lrc_text = if lrc {
+                    buf.clone()
+                } else {
+                    let xml = Document::from_str(&lyric.attributes.ttml.clone())
+                        .expect("Failed to parse xml");
+                    ttml_to_lrc(xml, &attributes.artist_name, &attributes.name)
+                        .context("Failed to convert")?
+                        .to_string()
+                };
+                self.embed_track_lyrics(&attributes.name, &attributes.artist_name, &lrc_text);
+            }
+
+            if self.translation {
+                self.write_variant_lyrics(
+                    &relation.translations,
+                    None,
+                    &attributes.name,
+                    &attributes.artist_name,
+                    "translation",
+                )?;
+            }
+            if self.romaji {
+                self.write_variant_lyrics(
+                    &relation.pronunciations,
+                    None,
+                    &attributes.name,
+                    &attributes.artist_name,
+                    "romaji",
+                )?;
+            }
+        } else if let Some(fallback_lyrics) = self
+            .fetch_fallback(
+                &attributes.artist_name,
+                &attributes.name,
+                "",
+                attributes
+                    .duration_in_millis
+                    .map(|ms| ms as f64 / 1000.0)
+                    .unwrap_or(0.0),
+            )
+            .await
+        {
+            let file_name = format!("{} - {}.lrc", attributes.name, attributes.artist_name);
+            let lrc_text = fallback_lyrics.to_string();
+            let mut file = std::fs::File::create(file_name).context("Failed to create file")?;
+            file.write_all(lrc_text.as_bytes())
+                .context("Failed to write file")?;
+
+            self.embed_track_lyrics(&attributes.name, &attributes.artist_name, &lrc_text);
         } else {
             println!("This song has no lyrics");
         }
@@ -307,7 +776,14 @@ impl Uta {
         Ok(())
     }
 
-    async fn new(token: String) -> Result<Self> {
+    async fn new(
+        token: String,
+        fallback: Option<FallbackProvider>,
+        refresh_token: bool,
+        embed_dir: Option<PathBuf>,
+        translation: bool,
+        romaji: bool,
+    ) -> Result<Self> {
         println!("Initializing...");
 
         let mut headers = header::HeaderMap::new();
@@ -346,6 +822,26 @@ impl Uta {
             .build()
             .context("Failed to build reqwest client")?;
 
+        if !refresh_token {
+            if let Some(cached) = session_cache::load(&token) {
+                println!("Reusing cached session...");
+
+                let fallback = build_fallback(fallback, &client);
+
+                return Ok(Uta {
+                    client,
+                    token,
+                    access_token: cached.bearer_token,
+                    store_front: cached.store_front,
+                    language: cached.language,
+                    fallback,
+                    embed_dir,
+                    translation,
+                    romaji,
+                });
+            }
+        }
+
         let main_page = client
             .get("https://music.apple.com/us/browse")
             .send()
@@ -393,12 +889,27 @@ impl Uta {
         let store_id = store_front.data[0].id.clone();
         let language = store_front.data[0].attributes.default_language_tag.clone();
 
+        if let Err(err) = session_cache::save(&session_cache::CachedSession {
+            media_user_token: token.clone(),
+            bearer_token: jwt.clone(),
+            store_front: store_id.clone(),
+            language: language.clone(),
+        }) {
+            println!("Failed to cache session: {}", err);
+        }
+
+        let fallback = build_fallback(fallback, &client);
+
         Ok(Uta {
             client,
             token,
             access_token: jwt,
             store_front: store_id,
             language,
+            fallback,
+            embed_dir,
+            translation,
+            romaji,
         })
     }
 }
@@ -406,8 +917,16 @@ impl Uta {
 #[tokio::main]
 async fn main() -> Result<()> {
     let args: Options = Options::parse();
-    let uta = Uta::new(args.token).await?;
-    uta.handle_raw_url(args.url, args.syllable, args.lrc)
+    let uta = Uta::new(
+        args.token,
+        args.fallback,
+        args.refresh_token,
+        args.embed,
+        args.translation,
+        args.romaji,
+    )
+    .await?;
+    uta.handle_raw_url(args.url, args.syllable, args.lrc, args.concurrency)
         .await
         .context("Failed to handle url")?;
     Ok(())
@@ -0,0 +1,133 @@
+use crate::lyrics_provider::{AccessDenied, LyricsProvider};
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use lrc::Lyrics;
+use reqwest::Client;
+use serde::Deserialize;
+use std::str::FromStr;
+
+const BASE_URL: &str = "https://apic-desktop.musixmatch.com/ws/1.1";
+
+#[derive(Deserialize)]
+struct Header {
+    status_code: i32,
+}
+
+#[derive(Deserialize)]
+struct Message<T> {
+    header: Header,
+    body: Option<T>,
+}
+
+#[derive(Deserialize)]
+struct Envelope<T> {
+    message: Message<T>,
+}
+
+#[derive(Deserialize)]
+struct TokenBody {
+    user_token: String,
+}
+
+#[derive(Deserialize)]
+struct SubtitleBody {
+    subtitle_list: Vec<SubtitleListItem>,
+}
+
+#[derive(Deserialize)]
+struct SubtitleListItem {
+    subtitle: Subtitle,
+}
+
+#[derive(Deserialize)]
+struct Subtitle {
+    subtitle_body: String,
+}
+
+/// A `LyricsProvider` backed by Musixmatch's (unofficial) desktop API, used as a fallback when
+/// Apple Music has no lyrics for a track.
+pub struct MusixmatchProvider {
+    client: Client,
+}
+
+impl MusixmatchProvider {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    async fn guest_token(&self) -> Result<String> {
+        let envelope: Envelope<TokenBody> = self
+            .client
+            .get(format!("{}/token.get", BASE_URL))
+            .query(&[("app_id", "web-desktop-app-v1.0")])
+            .send()
+            .await
+            .context("Failed to request Musixmatch guest token")?
+            .json()
+            .await
+            .context("Failed to parse Musixmatch guest token response")?;
+
+        let body = envelope
+            .message
+            .body
+            .context("Musixmatch returned no guest token")?;
+
+        Ok(body.user_token)
+    }
+}
+
+#[async_trait]
+impl LyricsProvider for MusixmatchProvider {
+    fn name(&self) -> &str {
+        "Musixmatch"
+    }
+
+    async fn fetch(
+        &self,
+        artist: &str,
+        title: &str,
+        album: &str,
+        duration_seconds: f64,
+    ) -> Result<Option<Lyrics>> {
+        let token = self.guest_token().await?;
+
+        let envelope: Envelope<SubtitleBody> = self
+            .client
+            .get(format!("{}/track.subtitles.get", BASE_URL))
+            .query(&[
+                ("app_id", "web-desktop-app-v1.0"),
+                ("usertoken", &token),
+                ("q_artist", artist),
+                ("q_track", title),
+                ("q_album", album),
+                ("q_duration", &duration_seconds.to_string()),
+                ("subtitle_format", "lrc"),
+            ])
+            .send()
+            .await
+            .context("Failed to send request to Musixmatch")?
+            .json()
+            .await
+            .context("Failed to parse Musixmatch response")?;
+
+        match envelope.message.header.status_code {
+            200 => {}
+            401 => bail!(AccessDenied),
+            404 => return Ok(None),
+            code => bail!("Musixmatch returned unexpected status code {}", code),
+        }
+
+        let Some(body) = envelope.message.body else {
+            return Ok(None);
+        };
+
+        let Some(item) = body.subtitle_list.into_iter().next() else {
+            return Ok(None);
+        };
+
+        let lyrics = Lyrics::from_str(&item.subtitle.subtitle_body)
+            .context("Failed to parse Musixmatch subtitle as lrc")?;
+
+        Ok(Some(lyrics))
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::{Context, Result};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Everything needed to skip the browse/JS-scrape/storefront round-trip on a repeat run.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CachedSession {
+    pub media_user_token: String,
+    pub bearer_token: String,
+    pub store_front: String,
+    pub language: String,
+}
+
+/// On-disk cache shape: keyed by `media_user_token` so switching between Apple accounts doesn't
+/// evict the other account's cached session.
+type Cache = HashMap<String, CachedSession>;
+
+#[derive(Deserialize)]
+struct JwtClaims {
+    exp: u64,
+}
+
+fn cache_path() -> Result<PathBuf> {
+    let dir = dirs::cache_dir().context("Failed to determine OS cache dir")?;
+    Ok(dir.join("uta").join("session.json"))
+}
+
+fn load_cache(path: &PathBuf) -> Cache {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn jwt_expired(jwt: &str) -> bool {
+    let Some(claims_segment) = jwt.split('.').nth(1) else {
+        return true;
+    };
+
+    let Ok(decoded) = URL_SAFE_NO_PAD.decode(claims_segment) else {
+        return true;
+    };
+
+    let Ok(claims) = serde_json::from_slice::<JwtClaims>(&decoded) else {
+        return true;
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    claims.exp <= now
+}
+
+/// Loads the cached session for this `media-user-token`, or `None` if there isn't one cached
+/// for this token, or its bearer JWT has already expired.
+pub fn load(media_user_token: &str) -> Option<CachedSession> {
+    let path = cache_path().ok()?;
+    let cache = load_cache(&path);
+    let session = cache.get(media_user_token)?.clone();
+
+    if jwt_expired(&session.bearer_token) {
+        return None;
+    }
+
+    Some(session)
+}
+
+/// Upserts `session` into the on-disk cache under its own `media_user_token`, leaving any other
+/// account's cached session untouched.
+pub fn save(session: &CachedSession) -> Result<()> {
+    let path = cache_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context("Failed to create session cache directory")?;
+    }
+
+    let mut cache = load_cache(&path);
+    cache.insert(session.media_user_token.clone(), session.clone());
+
+    let contents =
+        serde_json::to_string_pretty(&cache).context("Failed to serialize session cache")?;
+    fs::write(&path, contents).context("Failed to write session cache")?;
+
+    // The cache holds media-user-tokens and bearer JWTs in plaintext; keep it readable only
+    // by the owner regardless of the process umask.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600))
+            .context("Failed to restrict session cache permissions")?;
+    }
+
+    Ok(())
+}
@@ -31,6 +31,8 @@ pub struct SimpleCatlogAttributes {
     pub name: String,
     #[serde(rename = "artistName")]
     pub artist_name: String,
+    #[serde(rename = "durationInMillis")]
+    pub duration_in_millis: Option<u32>,
 }
 
 #[derive(Deserialize)]
@@ -43,8 +45,9 @@ pub struct SimpleLyricsData {
     pub attributes: SimpleLyricsAttribute,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Default)]
 pub struct SimpleLyrics {
+    #[serde(default)]
     pub data: Vec<SimpleLyricsData>,
 }
 
@@ -53,6 +56,12 @@ pub struct SimpleRelationships {
     pub lyrics: SimpleLyrics,
     #[serde(rename = "syllable-lyrics")]
     pub syllable_lyrics: SimpleLyrics,
+    // Only present in the storefront's translation language, or the track's own romanization;
+    // absent entirely for most tracks, hence the defaults.
+    #[serde(rename = "translations", default)]
+    pub translations: SimpleLyrics,
+    #[serde(rename = "pronunciations", default)]
+    pub pronunciations: SimpleLyrics,
 }
 
 impl SimpleRelationships {
@@ -74,6 +83,10 @@ pub struct SimpleAlbumTrack {
 #[derive(Deserialize)]
 pub struct SimpleAlbumTracks {
     pub data: Vec<SimpleAlbumTrack>,
+    // Present when Apple paginates the tracks list (playlists, mostly); a relative path to
+    // the next page, to be requested against the same host.
+    #[serde(default)]
+    pub next: Option<String>,
 }
 
 // 主要取歌词相关的
@@ -111,3 +124,23 @@ pub struct SongCatlogData {
 pub struct SongCatlogs {
     pub data: Vec<SongCatlogData>,
 }
+
+#[derive(Deserialize)]
+pub struct SimplePlaylistAttributes {
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+pub struct PlaylistCatlogData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub data_type: String, // r#type if you like
+    pub href: String,
+    pub attributes: SimplePlaylistAttributes,
+    pub relationships: SimpleAlbumRelationships,
+}
+
+#[derive(Deserialize)]
+pub struct PlaylistCatlogs {
+    pub data: Vec<PlaylistCatlogData>,
+}